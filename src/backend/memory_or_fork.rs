@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::cell::RefCell;
 use primitive_types::{H160, H256, U256};
 use sha3::{Digest, Keccak256};
 use super::{Basic, Backend, ApplyBackend, Apply, Log};
@@ -9,26 +10,224 @@ use futures::executor::block_on;
 use std::convert::TryFrom;
 use ethers::types::BlockNumber;
 
+/// Errors that can arise while resolving account or storage state through a
+/// [`ForkMemoryBackend`]'s remote provider.
+#[derive(Clone, Debug)]
+pub enum BackendError {
+	/// The JSON-RPC call to the provider failed (dropped connection, rate limit, etc).
+	Network(String),
+	/// The provider responded but the payload could not be decoded.
+	Decode(String),
+}
+
+impl core::fmt::Display for BackendError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			BackendError::Network(msg) => write!(f, "network error querying provider: {}", msg),
+			BackendError::Decode(msg) => write!(f, "could not decode provider response: {}", msg),
+		}
+	}
+}
+
+impl std::error::Error for BackendError {}
+
+/// Classify a provider error as a decode failure (the node responded but the payload
+/// couldn't be parsed) or a network failure (everything else: dropped connections, rate
+/// limits, timeouts).
+fn classify_provider_error<E: core::fmt::Display>(e: E) -> BackendError {
+	let msg = e.to_string();
+	let lower = msg.to_lowercase();
+	if lower.contains("deserializ") || lower.contains("decod") || lower.contains("parse") {
+		BackendError::Decode(msg)
+	} else {
+		BackendError::Network(msg)
+	}
+}
+
+/// Hex (de)serialization for `Option<Vec<u8>>` code fields, so a `PodState` snapshot
+/// emits canonical `0x`-prefixed hex for code bytes instead of a raw byte array.
+#[cfg(feature = "with-serde")]
+mod hex_bytes {
+	use alloc::format;
+	use alloc::string::String;
+	use alloc::vec::Vec;
+
+	pub fn serialize<S>(bytes: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match bytes {
+			Some(b) => {
+				let mut hex = String::with_capacity(2 + b.len() * 2);
+				hex.push_str("0x");
+				for byte in b {
+					hex.push_str(&format!("{:02x}", byte));
+				}
+				serializer.serialize_str(&hex)
+			},
+			None => serializer.serialize_none(),
+		}
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let raw: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+		match raw {
+			Some(s) => {
+				let trimmed = s.strip_prefix("0x").unwrap_or(s.as_str());
+				if trimmed.len() % 2 != 0 {
+					return Err(serde::de::Error::custom(format!(
+						"odd-length hex string for code bytes: {}", s
+					)));
+				}
+				let mut bytes = Vec::with_capacity(trimmed.len() / 2);
+				for i in (0..trimmed.len()).step_by(2) {
+					bytes.push(
+						u8::from_str_radix(&trimmed[i..i + 2], 16).map_err(serde::de::Error::custom)?
+					);
+				}
+				Ok(Some(bytes))
+			},
+			None => Ok(None),
+		}
+	}
+}
+
 /// Account information of a memory backend.
 #[derive(Default, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForkMemoryAccount {
 	/// Account nonce.
+	#[cfg_attr(feature = "with-serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub nonce: Option<U256>,
 	/// Account balance.
+	#[cfg_attr(feature = "with-serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub balance: Option<U256>,
 	/// Full account storage.
+	#[cfg_attr(feature = "with-serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub storage: Option<BTreeMap<H256, H256>>,
 	/// Account code.
+	#[cfg_attr(feature = "with-serde", serde(with = "hex_bytes", skip_serializing_if = "Option::is_none", default))]
 	pub code: Option<Vec<u8>>,
 }
 
+/// JSON-friendly snapshot of a [`ForkMemoryBackend`]'s cached state, modeled on
+/// OpenEthereum's `PodState`/`PodAccount` (`{ address: { balance, nonce, code, storage } }`).
+/// Round-tripping through [`ForkMemoryBackend::to_pod`]/[`ForkMemoryBackend::from_pod`] lets
+/// a prior fork session be replayed fully offline, without re-querying the node.
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PodState(pub BTreeMap<H160, ForkMemoryAccount>);
+
+/// A before/after comparison of a single value, mirroring OpenEthereum's account-diff
+/// `Diff` type. Equal `pre`/`post` values collapse to `Same` rather than `Changed`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Diff<T> {
+	/// The value is unchanged.
+	Same,
+	/// The value didn't exist before, but exists now.
+	Born(T),
+	/// The value existed both before and after, but changed.
+	Changed(T, T),
+	/// The value existed before, but is gone now.
+	Died(T),
+}
+
+impl<T: Eq> Diff<T> {
+	fn new(pre: Option<T>, post: Option<T>) -> Self {
+		match (pre, post) {
+			(None, None) => Diff::Same,
+			(None, Some(post)) => Diff::Born(post),
+			(Some(pre), None) => Diff::Died(pre),
+			(Some(pre), Some(post)) => {
+				if pre == post {
+					Diff::Same
+				} else {
+					Diff::Changed(pre, post)
+				}
+			},
+		}
+	}
+
+	fn is_same(&self) -> bool {
+		matches!(self, Diff::Same)
+	}
+}
+
+/// Per-account diff produced by [`ForkMemoryBackend::diff_apply`]. `storage` only
+/// contains slots whose value actually changed; unchanged slots are omitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountDiff {
+	/// How the account's balance changed.
+	pub balance: Diff<U256>,
+	/// How the account's nonce changed.
+	pub nonce: Diff<U256>,
+	/// How the account's code changed.
+	pub code: Diff<Vec<u8>>,
+	/// How each touched storage slot changed.
+	pub storage: BTreeMap<H256, Diff<H256>>,
+}
+
+impl AccountDiff {
+	/// Diff a single account's before/after snapshots, returning `None` if nothing
+	/// about it actually changed.
+	fn new(pre: Option<ForkMemoryAccount>, post: Option<ForkMemoryAccount>) -> Option<AccountDiff> {
+		let balance = Diff::new(pre.as_ref().and_then(|a| a.balance), post.as_ref().and_then(|a| a.balance));
+		let nonce = Diff::new(pre.as_ref().and_then(|a| a.nonce), post.as_ref().and_then(|a| a.nonce));
+		let code = Diff::new(pre.as_ref().and_then(|a| a.code.clone()), post.as_ref().and_then(|a| a.code.clone()));
+
+		let pre_storage = pre.and_then(|a| a.storage).unwrap_or_default();
+		let post_storage = post.and_then(|a| a.storage).unwrap_or_default();
+
+		// EVM storage is dense: an absent slot and a slot explicitly cached as
+		// `H256::default()` both mean "reads as zero". `apply` strips zero-valued
+		// entries out of the map as a normalization side effect, so comparing by key
+		// presence would misreport that as a slot dying. Resolve both sides to their
+		// effective value first and only report a genuine change.
+		let touched: BTreeSet<H256> = pre_storage.keys().chain(post_storage.keys()).cloned().collect();
+		let mut storage = BTreeMap::new();
+		for slot in touched {
+			let pre_value = pre_storage.get(&slot).cloned().unwrap_or_default();
+			let post_value = post_storage.get(&slot).cloned().unwrap_or_default();
+			if pre_value != post_value {
+				storage.insert(slot, Diff::new(Some(pre_value), Some(post_value)));
+			}
+		}
+
+		if balance.is_same() && nonce.is_same() && code.is_same() && storage.is_empty() {
+			None
+		} else {
+			Some(AccountDiff { balance, nonce, code, storage })
+		}
+	}
+}
+
+/// A structured diff of every account and storage slot touched across one or more
+/// `apply` calls, as produced by [`ForkMemoryBackend::diff_apply`].
+pub type StateDiff = BTreeMap<H160, AccountDiff>;
 
 /// Memory backend, storing all state values in a `BTreeMap` in memory.
 #[derive(Clone, Debug)]
 pub struct ForkMemoryBackend<'vicinity> {
 	vicinity: &'vicinity MemoryVicinity,
-	state: BTreeMap<H160, ForkMemoryAccount>,
+	/// Accounts fetched from the node are cached here so repeated reads of the
+	/// same account or slot are served from memory instead of re-hitting the
+	/// provider. Wrapped in a `RefCell` since the `Backend` trait's read
+	/// methods only take `&self`.
+	state: RefCell<BTreeMap<H160, ForkMemoryAccount>>,
+	/// Stack of open checkpoints. Each frame records, for every address touched since
+	/// the frame was opened, the value that address had immediately before (`None` if
+	/// the address was absent from `state`), so a checkpoint can be rolled back.
+	checkpoints: RefCell<Vec<BTreeMap<H160, Option<ForkMemoryAccount>>>>,
+	/// When `Some`, records the pre-image of every address mutated by `apply` (but not
+	/// by lazily-cached reads) while [`diff_apply`](Self::diff_apply) is running, kept
+	/// independent of `checkpoints` so a read that merely warms the cache never shows
+	/// up as a write in the reported diff.
+	diff_journal: RefCell<Option<BTreeMap<H160, Option<ForkMemoryAccount>>>>,
 	logs: Vec<Log>,
 	provider: Provider<Http>,
 	block: Option<BlockNumber>
@@ -39,7 +238,9 @@ impl<'vicinity> ForkMemoryBackend<'vicinity> {
 	pub fn new(vicinity: &'vicinity MemoryVicinity, state: BTreeMap<H160, ForkMemoryAccount>, provider: String, bn: Option<BlockNumber>) -> Self {
 		Self {
 			vicinity,
-			state,
+			state: RefCell::new(state),
+			checkpoints: RefCell::new(Vec::new()),
+			diff_journal: RefCell::new(None),
 			logs: Vec::new(),
 			provider: Provider::<Http>::try_from(provider).expect("Could not connect to HTTP Provider"),
 			block: bn,
@@ -47,8 +248,184 @@ impl<'vicinity> ForkMemoryBackend<'vicinity> {
 	}
 
 	/// Get the underlying `BTreeMap` storing the state.
-	pub fn state(&self) -> &BTreeMap<H160, ForkMemoryAccount> {
-		&self.state
+	pub fn state(&self) -> core::cell::Ref<'_, BTreeMap<H160, ForkMemoryAccount>> {
+		self.state.borrow()
+	}
+
+	/// Export everything fetched from the node so far as a `PodState` snapshot, so it
+	/// can be serialized and replayed later without re-hitting the provider.
+	pub fn to_pod(&self) -> PodState {
+		PodState(self.state.borrow().clone())
+	}
+
+	/// Pre-populate `self.state` from a `PodState` snapshot. Accounts and slots present
+	/// in the snapshot are served from memory; anything missing still falls back to the
+	/// node as usual.
+	pub fn from_pod(&mut self, pod: PodState) {
+		self.state.get_mut().extend(pod.0);
+	}
+
+	/// Record the pre-image of `address` into the innermost open checkpoint frame, if
+	/// any, the first time it is touched within that frame.
+	fn journal(&self, state: &BTreeMap<H160, ForkMemoryAccount>, address: H160) {
+		if let Some(frame) = self.checkpoints.borrow_mut().last_mut() {
+			frame.entry(address).or_insert_with(|| state.get(&address).cloned());
+		}
+	}
+
+	/// Record the pre-image of `address` into the active [`diff_apply`](Self::diff_apply)
+	/// journal, if one is running. Only called from `apply`, never from the lazily-cached
+	/// reads, so a read that merely warms the cache is never reported as a write.
+	fn journal_diff(&self, state: &BTreeMap<H160, ForkMemoryAccount>, address: H160) {
+		if let Some(frame) = self.diff_journal.borrow_mut().as_mut() {
+			frame.entry(address).or_insert_with(|| state.get(&address).cloned());
+		}
+	}
+
+	/// Open a new checkpoint and return its id. Every account mutated after this call,
+	/// whether through applied state transitions or lazily-cached remote reads, is
+	/// journaled so it can be undone with [`revert_to_checkpoint`](Self::revert_to_checkpoint).
+	pub fn checkpoint(&mut self) -> usize {
+		let mut checkpoints = self.checkpoints.borrow_mut();
+		checkpoints.push(BTreeMap::new());
+		checkpoints.len() - 1
+	}
+
+	/// Undo every mutation journaled since checkpoint `id` was opened, discarding `id`
+	/// and any checkpoints nested inside it.
+	pub fn revert_to_checkpoint(&mut self, id: usize) {
+		let mut checkpoints = self.checkpoints.borrow_mut();
+		let mut state = self.state.borrow_mut();
+		assert!(id < checkpoints.len(), "revert_to_checkpoint: no such checkpoint");
+
+		while checkpoints.len() > id {
+			let frame = checkpoints.pop().expect("checkpoint stack underflow");
+			for (address, preimage) in frame {
+				match preimage {
+					Some(account) => { state.insert(address, account); },
+					None => { state.remove(&address); },
+				}
+			}
+		}
+	}
+
+	/// Canonicalize the innermost open checkpoint into the one below it, or drop it
+	/// entirely if it is the outermost checkpoint. Its mutations are kept; only the
+	/// ability to revert them in isolation is lost.
+	pub fn commit_checkpoint(&mut self) {
+		let mut checkpoints = self.checkpoints.borrow_mut();
+		let frame = checkpoints.pop().expect("commit_checkpoint: no open checkpoint");
+
+		if let Some(parent) = checkpoints.last_mut() {
+			for (address, preimage) in frame {
+				parent.entry(address).or_insert(preimage);
+			}
+		}
+	}
+
+	/// Run `f` (one or more `apply` calls) against this backend and return a
+	/// [`StateDiff`] classifying every address `apply` actually mutated as `Same`,
+	/// `Born`, `Changed` or `Died`, reusing the account-diff model OpenEthereum uses
+	/// for trace reporting. Pre-images are taken from a journal that only `apply`
+	/// writes to, independent of `checkpoints`, so an account merely read (and
+	/// lazily cached) by execution inside `f` is never mistaken for a write. Not
+	/// reentrant: `f` must not call `diff_apply` itself.
+	pub fn diff_apply<F: FnOnce(&mut Self)>(&mut self, f: F) -> StateDiff {
+		*self.diff_journal.borrow_mut() = Some(BTreeMap::new());
+		f(self);
+		let frame = self.diff_journal.borrow_mut().take()
+			.expect("diff journal was active at the start of diff_apply");
+
+		let mut diff = StateDiff::new();
+		let state = self.state.borrow();
+		for (address, preimage) in frame {
+			let post = state.get(&address).cloned();
+			if let Some(account_diff) = AccountDiff::new(preimage, post) {
+				diff.insert(address, account_diff);
+			}
+		}
+
+		diff
+	}
+
+	/// Fallible version of [`Backend::basic`]. Returns `Err(BackendError)` instead of
+	/// panicking when the remote provider call fails.
+	pub fn try_basic(&self, address: H160) -> Result<Basic, BackendError> {
+		let cached = self.state.borrow().get(&address).cloned();
+
+		let balance = match cached.as_ref().and_then(|a| a.balance) {
+			Some(balance) => balance,
+			None => block_on(self.provider.get_balance(address, self.block))
+				.map_err(classify_provider_error)?,
+		};
+
+		let nonce = match cached.as_ref().and_then(|a| a.nonce) {
+			Some(nonce) => nonce,
+			None => block_on(self.provider.get_transaction_count(address, self.block))
+				.map_err(classify_provider_error)?,
+		};
+
+		// Only land the fetched values in the cache now that both calls have
+		// succeeded, so a failed fetch never leaves a phantom cache entry behind.
+		let mut state = self.state.borrow_mut();
+		self.journal(&state, address);
+		let account = state.entry(address).or_default();
+		account.balance = Some(balance);
+		account.nonce = Some(nonce);
+
+		Ok(Basic { balance, nonce })
+	}
+
+	/// Fallible version of [`Backend::code`].
+	pub fn try_code(&self, address: H160) -> Result<Vec<u8>, BackendError> {
+		let cached = self.state.borrow().get(&address).and_then(|a| a.code.clone());
+
+		let code = match cached {
+			Some(code) => code,
+			None => block_on(self.provider.get_code(address, self.block))
+				.map_err(classify_provider_error)?
+				.as_ref()
+				.to_vec(),
+		};
+
+		let mut state = self.state.borrow_mut();
+		self.journal(&state, address);
+		state.entry(address).or_default().code = Some(code.clone());
+
+		Ok(code)
+	}
+
+	/// Fallible version of [`Backend::code_hash`].
+	pub fn try_code_hash(&self, address: H160) -> Result<H256, BackendError> {
+		Ok(H256::from_slice(Keccak256::digest(&self.try_code(address)?).as_slice()))
+	}
+
+	/// Fallible version of [`Backend::code_size`].
+	pub fn try_code_size(&self, address: H160) -> Result<usize, BackendError> {
+		Ok(self.try_code(address)?.len())
+	}
+
+	/// Fallible version of [`Backend::storage`].
+	pub fn try_storage(&self, address: H160, index: H256) -> Result<H256, BackendError> {
+		let cached = self.state.borrow()
+			.get(&address)
+			.and_then(|a| a.storage.as_ref())
+			.and_then(|s| s.get(&index))
+			.cloned();
+
+		let value = match cached {
+			Some(value) => value,
+			None => block_on(self.provider.get_storage_at(address, index, self.block))
+				.map_err(classify_provider_error)?,
+		};
+
+		let mut state = self.state.borrow_mut();
+		self.journal(&state, address);
+		state.entry(address).or_default()
+			.storage.get_or_insert_with(BTreeMap::new)
+			.insert(index, value);
+
+		Ok(value)
 	}
 }
 
@@ -74,164 +451,44 @@ impl<'vicinity> Backend for ForkMemoryBackend<'vicinity> {
 	fn chain_id(&self) -> U256 { self.vicinity.chain_id }
 
 	fn exists(&self, address: H160) -> bool {
-		self.state.contains_key(&address)
+		// A cache-warming read (e.g. a SLOAD against an address that turns out to have
+		// no balance/nonce/code) still lands an entry in `state`, but that alone must
+		// not imply existence — otherwise every touched-but-nonexistent address would
+		// pass EIP-161 emptiness / CREATE2 collision checks. Only a non-empty account
+		// (per EIP-161: non-zero nonce or balance, or non-empty code) counts as existing.
+		match self.state.borrow().get(&address) {
+			Some(account) => {
+				account.nonce.map_or(false, |nonce| !nonce.is_zero())
+					|| account.balance.map_or(false, |balance| !balance.is_zero())
+					|| account.code.as_ref().map_or(false, |code| !code.is_empty())
+			},
+			None => false,
+		}
 	}
 
 	fn basic(&self, address: H160) -> Basic {
-		let mut account;
-		if let Some(acct) = self.state.get(&address) {
-			account = acct.clone();
-		} else {
-			account = ForkMemoryAccount {
-				balance: Some(block_on(
-								self.provider.get_balance(address, self.block)
-							).expect(&format!("Could not get balance for account: {:} from state or node", address))),
-				nonce: Some(block_on(
-						self.provider.get_transaction_count(address, self.block)
-					).expect(&format!("Could not get nonce for account: {:} from state or node", address))),
-				storage: None,
-				code: None,
-			}
-		};
-
-		let mut b = Basic {
-			balance: U256::zero(),
-			nonce: U256::zero(),
-		};
-		if let Some(balance) = account.balance {
-			b.balance = balance;
-		} else {
-			account.balance = Some(block_on(
-				self.provider.get_balance(address, self.block)
-			).expect(&format!("Could not get balance for account: {:} from state or node", address)));
-			b.balance = account.balance.unwrap();
-		}
-
-		if let Some(nonce) = account.nonce {
-			b.nonce = nonce;
-		} else {
-			account.nonce = Some(block_on(
-				self.provider.get_transaction_count(address, self.block)
-			).expect(&format!("Could not get nonce for account: {:} from state or node", address)));
-			b.nonce = account.nonce.unwrap();
-		}
-		b
+		self.try_basic(address)
+			.expect(&format!("Could not get balance or nonce for account: {:} from state or node", address))
 	}
 
 	fn code_hash(&self, address: H160) -> H256 {
-		let mut account;
-		if let Some(acct) = self.state.get(&address) {
-			account = acct.clone();
-		} else {
-			account = ForkMemoryAccount {
-				balance: None,
-				nonce: None,
-				storage: None,
-				code: Some(block_on(self.provider.get_code(address, self.block))
-					.expect(&format!("Could not get code for {:?}", address))
-					.as_ref()
-					.to_vec()),
-			};
-		};
-
-		let code;
-		if let Some(acct_code) = account.code.clone() {
-			code = acct_code;
-		} else {
-			account.code = Some(block_on(self.provider.get_code(address, self.block))
-				.expect(&format!("Could not get code for {:?}", address))
-				.as_ref()
-				.to_vec());
-			code = account.code.clone().unwrap();
-		}
-		H256::from_slice(Keccak256::digest(&code).as_slice())
+		self.try_code_hash(address)
+			.expect(&format!("Could not get code for {:?}", address))
 	}
 
 	fn code_size(&self, address: H160) -> usize {
-		let mut account;
-		if let Some(acct) = self.state.get(&address) {
-			account = acct.clone();
-		} else {
-			account = ForkMemoryAccount {
-				balance: None,
-				nonce: None,
-				storage: None,
-				code: Some(block_on(self.provider.get_code(address, self.block))
-					.expect(&format!("Could not get code for {:?}", address))
-					.as_ref()
-					.to_vec()),
-			};
-		};
-
-		let code;
-		if let Some(acct_code) = account.code.clone() {
-			code = acct_code;
-		} else {
-			account.code = Some(block_on(self.provider.get_code(address, self.block))
-				.expect(&format!("Could not get code for {:?}", address))
-				.as_ref()
-				.to_vec());
-			code = account.code.clone().unwrap();
-		}
-		code.len()
+		self.try_code_size(address)
+			.expect(&format!("Could not get code for {:?}", address))
 	}
 
 	fn code(&self, address: H160) -> Vec<u8> {
-		let mut account;
-		if let Some(acct) = self.state.get(&address) {
-			account = acct.clone();
-		} else {
-			account = ForkMemoryAccount {
-				balance: None,
-				nonce: None,
-				storage: None,
-				code: Some(block_on(self.provider.get_code(address, self.block))
-					.expect(&format!("Could not get code for {:?}", address))
-					.as_ref()
-					.to_vec()),
-			};
-		};
-
-		let code;
-		if let Some(acct_code) = account.code.clone() {
-			code = acct_code;
-		} else {
-			account.code = Some(block_on(self.provider.get_code(address, self.block))
-				.expect(&format!("Could not get code for {:?}", address))
-				.as_ref()
-				.to_vec());
-			code = account.code.clone().unwrap();
-		}
-		code
+		self.try_code(address)
+			.expect(&format!("Could not get code for {:?}", address))
 	}
 
 	fn storage(&self, address: H160, index: H256) -> H256 {
-		let mut account;
-		if let Some(acct) = self.state.get(&address) {
-			account = acct.clone();
-		} else {
-			account = ForkMemoryAccount {
-				balance: None,
-				nonce: None,
-				storage: Some(BTreeMap::new()),
-				code: None,
-			};
-		};
-
-		let mut storage: BTreeMap<H256, H256> = BTreeMap::new();
-		let val;
-		if let Some(mut acct_storage) = account.storage.clone() {
-			val = acct_storage.entry(index).or_insert(
-				block_on(self.provider.get_storage_at(address, index, None)).expect("Could not get slot for address")
-			).clone();
-			storage = acct_storage;
-		} else {
-			val = storage.entry(index).or_insert({
-				block_on(self.provider.get_storage_at(address, index, None)).expect("Could not get slot for address")
-			}).clone();
-		}
-		account.storage = Some(storage);
-		val
+		self.try_storage(address, index)
+			.expect("Could not get slot for address")
 	}
 }
 
@@ -246,13 +503,17 @@ impl<'vicinity> ApplyBackend for ForkMemoryBackend<'vicinity> {
 		I: IntoIterator<Item=(H256, H256)>,
 		L: IntoIterator<Item=Log>,
 	{
+		let mut state = self.state.borrow_mut();
+
 		for apply in values {
 			match apply {
 				Apply::Modify {
 					address, basic, code, storage, reset_storage,
 				} => {
+					self.journal(&state, address);
+					self.journal_diff(&state, address);
 					let is_empty = {
-						let account = self.state.entry(address).or_insert(Default::default());
+						let account = state.entry(address).or_insert(Default::default());
 						account.balance = Some(basic.balance);
 						account.nonce = Some(basic.nonce);
 						if let Some(code) = code {
@@ -287,13 +548,15 @@ impl<'vicinity> ApplyBackend for ForkMemoryBackend<'vicinity> {
 					};
 
 					if is_empty && delete_empty {
-						self.state.remove(&address);
+						state.remove(&address);
 					}
 				},
 				Apply::Delete {
 					address,
 				} => {
-					self.state.remove(&address);
+					self.journal(&state, address);
+					self.journal_diff(&state, address);
+					state.remove(&address);
 				},
 			}
 		}